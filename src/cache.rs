@@ -0,0 +1,118 @@
+use std::{collections::HashMap, fs};
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use tracing::{debug, warn};
+
+use crate::{settings::SETTINGS, translator::LanguagePair};
+
+/// In-memory translation-memory cache, keyed on the language pair and a normalized
+/// version of the source text, so repeated phrases skip the model entirely.
+static TRANSLATION_MEMORY: Lazy<DashMap<(LanguagePair, String), String>> = Lazy::new(DashMap::new);
+
+/// Normalizes text for use as a translation-memory key: trims the input and collapses
+/// internal whitespace, so e.g. "Ciao " and "Ciao" hit the same cache entry.
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Looks up a previously computed translation for `text` in the given language `pair`.
+pub fn get(pair: LanguagePair, text: &str) -> Option<String> {
+    TRANSLATION_MEMORY
+        .get(&(pair, normalize(text)))
+        .map(|entry| entry.value().clone())
+}
+
+/// Stores a translation for `text` in the given language `pair`.
+pub fn insert(pair: LanguagePair, text: &str, translation: String) {
+    TRANSLATION_MEMORY.insert((pair, normalize(text)), translation);
+}
+
+/// The name of the glossary file for a given language pair, loaded from
+/// `Settings.path` at startup, e.g. `glossary.italian-english.json`.
+fn glossary_file_name(pair: LanguagePair) -> String {
+    format!(
+        "glossary.{:?}-{:?}.json",
+        pair.from, pair.to
+    )
+    .to_lowercase()
+}
+
+/// Loads the optional JSON glossary for `pair`, mapping exact source strings to
+/// preferred translations that override model output for domain terms. Returns an
+/// empty glossary if the file is missing or fails to parse.
+pub fn load_glossary(pair: LanguagePair) -> HashMap<String, String> {
+    let path = SETTINGS.path.join(glossary_file_name(pair));
+
+    let Ok(contents) = fs::read_to_string(&path) else {
+        debug!("No glossary found at {path:?}, skipping");
+        return HashMap::new();
+    };
+
+    parse_glossary(&contents).unwrap_or_else(|error| {
+        warn!("Failed to parse glossary at {path:?}: {error}");
+        HashMap::new()
+    })
+}
+
+/// Parses a glossary file's JSON contents, split out from [`load_glossary`] so the
+/// parsing itself is testable without touching the filesystem.
+fn parse_glossary(contents: &str) -> Result<HashMap<String, String>, serde_json::Error> {
+    serde_json::from_str(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_collapses_internal_and_surrounding_whitespace() {
+        assert_eq!(normalize("  Hello   world  "), "Hello world");
+        assert_eq!(normalize("Ciao"), "Ciao");
+        assert_eq!(normalize("Ciao\n\tbello"), "Ciao bello");
+    }
+
+    #[test]
+    fn get_returns_none_before_insert_and_the_value_after() {
+        let pair = LanguagePair {
+            from: crate::translator::Language::English,
+            to: crate::translator::Language::Italian,
+        };
+
+        assert_eq!(get(pair, "cache test phrase one"), None);
+
+        insert(pair, "cache test phrase one", "frase di prova".to_owned());
+
+        assert_eq!(
+            get(pair, "cache test phrase one"),
+            Some("frase di prova".to_owned())
+        );
+        // A normalization-equivalent lookup should hit the same entry.
+        assert_eq!(
+            get(pair, "  cache   test phrase one  "),
+            Some("frase di prova".to_owned())
+        );
+    }
+
+    #[test]
+    fn glossary_file_name_is_lowercase_pair_based() {
+        let pair = LanguagePair {
+            from: crate::translator::Language::Italian,
+            to: crate::translator::Language::English,
+        };
+
+        assert_eq!(glossary_file_name(pair), "glossary.italian-english.json");
+    }
+
+    #[test]
+    fn parse_glossary_reads_valid_json() {
+        let glossary = parse_glossary(r#"{"Ciao": "Hi there"}"#).unwrap();
+
+        assert_eq!(glossary.get("Ciao"), Some(&"Hi there".to_owned()));
+    }
+
+    #[test]
+    fn parse_glossary_rejects_invalid_json() {
+        assert!(parse_glossary("not json").is_err());
+    }
+}
@@ -1,35 +1,66 @@
-use tracing::debug;
+use std::collections::HashMap;
 
-use crate::translator::{TranslationDirection, Translator};
+use futures::future::join_all;
+use tracing::{debug, warn};
+
+use crate::{
+    settings::SETTINGS,
+    translator::{LanguagePair, Readiness, TranslationDirection, Translator},
+};
 
 /// Application context
 #[derive(Debug)]
 pub struct AppContext {
-    /// English to Italian model translation
-    pub en_it: Translator,
-    /// Italian to English model translation
-    pub it_en: Translator,
+    /// Translators for every configured language pair, keyed on `(from, to)`.
+    translators: HashMap<LanguagePair, Translator>,
+}
+
+impl AppContext {
+    /// Returns the translator configured for the given language pair, if any.
+    pub fn translator_for(&self, pair: LanguagePair) -> Option<&Translator> {
+        self.translators.get(&pair)
+    }
+
+    /// Reports the current readiness of every configured translator, for the `/health` endpoint.
+    pub fn readiness(&self) -> Vec<(LanguagePair, Readiness)> {
+        self.translators
+            .iter()
+            .map(|(pair, translator)| (*pair, translator.readiness()))
+            .collect()
+    }
 }
 
-/// Prepares an instance of the Application context
+/// Prepares an instance of the Application context, waiting for every configured
+/// translator's model to finish loading before returning so the server only starts
+/// accepting traffic once translation is actually possible.
 #[tracing::instrument]
-pub fn prepare_app_context() -> AppContext {
+pub async fn prepare_app_context() -> AppContext {
     // Add trace for initializing the AppContext
     debug!("Initializing AppContext");
 
-    // Spawn a new English to Italian translation process and get its handle and resulting model
-    let en_it = Translator::spawn(TranslationDirection::EnglishToItalian);
+    let translators: HashMap<LanguagePair, Translator> = SETTINGS
+        .language_pairs
+        .iter()
+        .map(|config| {
+            let direction =
+                TranslationDirection::new(config.from, config.to, config.model_dir.clone());
+            let pair = direction.pair;
 
-    // Add trace for spawning the English to Italian translation process
-    debug!("Spawning English to Italian Translator process");
+            // Add trace for spawning each configured Translator process
+            debug!("Spawning Translator process for {:?}", pair);
 
-    // Spawn a new Italian to English translation process and get its handle and resulting model
-    let it_en = Translator::spawn(TranslationDirection::ItalianToEnglish);
+            (pair, Translator::spawn(direction))
+        })
+        .collect();
 
-    // Add trace for spawning the Italian to English translation process
-    debug!("Spawning Italian to English Translator process");
+    join_all(translators.iter().map(|(pair, translator)| async move {
+        if let Err(error) = translator.ready().await {
+            warn!("Translator for {:?} failed to initialize: {}", pair, error);
+        }
+    }))
+    .await;
 
-    AppContext { en_it, it_en }
+    AppContext { translators }
 }
 
 #[cfg(test)]
@@ -38,6 +69,6 @@ mod tests {
 
     #[actix_web::test]
     async fn prepare_app_context_should_not_panic() {
-        prepare_app_context();
+        prepare_app_context().await;
     }
 }
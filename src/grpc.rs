@@ -0,0 +1,162 @@
+//! gRPC translation service, exposing the same `AppContext` translators as the REST API.
+//!
+//! `Translate` mirrors the REST `/translate` endpoint, while `StreamingTranslate` splits
+//! the input into sentences and yields each one as soon as it's translated, so clients
+//! submitting a long document get results incrementally rather than waiting for it all.
+
+use std::{pin::Pin, sync::Arc};
+
+use futures::Stream;
+use tokio::sync::mpsc;
+use tonic::{Request, Response, Status};
+
+use crate::{
+    context::AppContext,
+    translator::{Language as DomainLanguage, LanguagePair, Translator},
+};
+
+pub mod proto {
+    tonic::include_proto!("translation");
+}
+
+use proto::{
+    translation_server::{Translation, TranslationServer},
+    Language as ProtoLanguage, TranslateRequest, TranslateResponse,
+};
+
+impl From<ProtoLanguage> for DomainLanguage {
+    fn from(language: ProtoLanguage) -> Self {
+        match language {
+            ProtoLanguage::English => DomainLanguage::English,
+            ProtoLanguage::Italian => DomainLanguage::Italian,
+            ProtoLanguage::French => DomainLanguage::French,
+            ProtoLanguage::Spanish => DomainLanguage::Spanish,
+            ProtoLanguage::Portuguese => DomainLanguage::Portuguese,
+        }
+    }
+}
+
+/// Implements the `Translation` gRPC service on top of the shared `AppContext`.
+pub struct TranslationService {
+    app_context: Arc<AppContext>,
+}
+
+impl TranslationService {
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        Self { app_context }
+    }
+
+    /// Wraps this service in the generated `tonic` server type, ready to register
+    /// with a `tonic::transport::Server`.
+    pub fn into_server(self) -> TranslationServer<Self> {
+        TranslationServer::new(self)
+    }
+
+    fn resolve(&self, from_language: i32, to_language: i32) -> Result<&Translator, Status> {
+        let from = ProtoLanguage::try_from(from_language)
+            .map_err(|_| Status::invalid_argument("unknown fromLanguage"))?;
+        let to = ProtoLanguage::try_from(to_language)
+            .map_err(|_| Status::invalid_argument("unknown toLanguage"))?;
+
+        let pair = LanguagePair {
+            from: from.into(),
+            to: to.into(),
+        };
+
+        self.app_context.translator_for(pair).ok_or_else(|| {
+            Status::invalid_argument(format!("no translator configured for {pair:?}"))
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl Translation for TranslationService {
+    async fn translate(
+        &self,
+        request: Request<TranslateRequest>,
+    ) -> Result<Response<TranslateResponse>, Status> {
+        let TranslateRequest {
+            text,
+            from_language,
+            to_language,
+        } = request.into_inner();
+
+        let translator = self.resolve(from_language, to_language)?;
+
+        let translation = translator
+            .translate(text)
+            .await
+            .map_err(|error| Status::internal(error.to_string()))?
+            .join(" ")
+            .trim()
+            .to_owned();
+
+        Ok(Response::new(TranslateResponse { translation }))
+    }
+
+    type StreamingTranslateStream =
+        Pin<Box<dyn Stream<Item = Result<TranslateResponse, Status>> + Send>>;
+
+    async fn streaming_translate(
+        &self,
+        request: Request<TranslateRequest>,
+    ) -> Result<Response<Self::StreamingTranslateStream>, Status> {
+        let TranslateRequest {
+            text,
+            from_language,
+            to_language,
+        } = request.into_inner();
+
+        // Resolved eagerly so an unsupported pair is rejected before the stream opens,
+        // rather than surfacing as the first streamed item.
+        self.resolve(from_language, to_language)?;
+
+        let sentences: Vec<String> = text
+            .split_inclusive(['.', '!', '?'])
+            .map(str::trim)
+            .filter(|sentence| !sentence.is_empty())
+            .map(str::to_owned)
+            .collect();
+
+        let app_context = Arc::clone(&self.app_context);
+        let pair = LanguagePair {
+            from: ProtoLanguage::try_from(from_language)
+                .expect("already validated by resolve")
+                .into(),
+            to: ProtoLanguage::try_from(to_language)
+                .expect("already validated by resolve")
+                .into(),
+        };
+
+        // Translate and send each sentence as soon as it resolves, instead of collecting a
+        // batch first: the client starts receiving results while later sentences are still
+        // being translated.
+        let (sender, receiver) = mpsc::channel(sentences.len().max(1));
+        tokio::spawn(async move {
+            let Some(translator) = app_context.translator_for(pair) else {
+                return;
+            };
+
+            for sentence in sentences {
+                let item = translator
+                    .translate(sentence)
+                    .await
+                    .map(|translation| TranslateResponse {
+                        translation: translation.join(" ").trim().to_owned(),
+                    })
+                    .map_err(|error| Status::internal(error.to_string()));
+
+                if sender.send(item).await.is_err() {
+                    // Receiver dropped (client disconnected); stop translating the rest.
+                    break;
+                }
+            }
+        });
+
+        let stream = futures::stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|item| (item, receiver))
+        });
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
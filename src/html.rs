@@ -0,0 +1,142 @@
+use ego_tree::{NodeId, NodeRef};
+use scraper::{ElementRef, Html, Node};
+
+/// Tags whose text content is not meant to be translated (scripts, styles, ...).
+const NON_TRANSLATABLE_TAGS: [&str; 2] = ["script", "style"];
+
+/// Collects the ids and current contents of every translatable text node in `document`,
+/// in document order. Whitespace-only text nodes and text nested under
+/// [`NON_TRANSLATABLE_TAGS`] are skipped so tag/attribute structure stays untouched.
+pub fn extract_text_nodes(document: &Html) -> (Vec<NodeId>, Vec<String>) {
+    let mut ids = Vec::new();
+    let mut texts = Vec::new();
+
+    for node in document.tree.nodes() {
+        let Node::Text(text) = node.value() else {
+            continue;
+        };
+
+        if text.trim().is_empty() {
+            continue;
+        }
+
+        let under_non_translatable_tag = node.ancestors().any(|ancestor| {
+            ancestor
+                .value()
+                .as_element()
+                .is_some_and(|element| NON_TRANSLATABLE_TAGS.contains(&element.name()))
+        });
+        if under_non_translatable_tag {
+            continue;
+        }
+
+        ids.push(node.id());
+        texts.push(text.to_string());
+    }
+
+    (ids, texts)
+}
+
+/// Re-inserts translated text back into the original DOM positions, leaving every
+/// other node (tags, attributes, entities) untouched.
+pub fn reinsert_translations(document: &mut Html, ids: &[NodeId], translations: Vec<String>) {
+    for (id, translation) in ids.iter().zip(translations) {
+        if let Some(mut node) = document.tree.get_mut(*id) {
+            if let Node::Text(text) = node.value() {
+                *text = translation.into();
+            }
+        }
+    }
+}
+
+/// Serializes `document`'s actual content, without the synthetic `<html>`/`<body>`
+/// wrapper that `scraper::Html::parse_fragment` builds around a fragment's nodes.
+/// Without this, a fragment like `<p>Ciao</p>` would come back out as
+/// `<html><body><p>Ciao</p></body></html>` instead of round-tripping untouched.
+pub fn serialize_fragment(document: &Html) -> String {
+    fragment_content(document)
+        .map(|node| match node.value() {
+            Node::Element(_) => ElementRef::wrap(node)
+                .map(|element| element.html())
+                .unwrap_or_default(),
+            Node::Text(text) => escape_text(text),
+            _ => String::new(),
+        })
+        .collect()
+}
+
+/// Walks past the synthetic `<html>` and `<body>` elements `parse_fragment` inserts, if
+/// present, returning the fragment's real top-level nodes.
+fn fragment_content(document: &Html) -> impl Iterator<Item = NodeRef<'_, Node>> {
+    let mut children: Vec<_> = document.tree.root().children().collect();
+
+    if let [html] = children.as_slice() {
+        if html.value().as_element().is_some_and(|element| element.name() == "html") {
+            children = html.children().collect();
+        }
+    }
+
+    if let Some(body) = children
+        .iter()
+        .find(|node| node.value().as_element().is_some_and(|element| element.name() == "body"))
+    {
+        children = body.children().collect();
+    }
+
+    children.into_iter()
+}
+
+/// Escapes the handful of characters that are unsafe to emit verbatim in HTML text
+/// content, for the rare case a fragment's top-level node is bare text rather than an
+/// element (elements are serialized, and already escaped, by `ElementRef::html`).
+fn escape_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_text_nodes_skips_whitespace_and_non_translatable_tags() {
+        let document = Html::parse_fragment(
+            "<p>Hello</p> <script>ignoreMe();</script><p>  </p><style>.x{}</style><p>World</p>",
+        );
+
+        let (ids, texts) = extract_text_nodes(&document);
+
+        assert_eq!(texts, vec!["Hello".to_owned(), "World".to_owned()]);
+        assert_eq!(ids.len(), texts.len());
+    }
+
+    #[test]
+    fn reinsert_translations_replaces_text_by_id_in_order() {
+        let mut document = Html::parse_fragment("<p>Hello</p><p>World</p>");
+        let (ids, _) = extract_text_nodes(&document);
+
+        reinsert_translations(
+            &mut document,
+            &ids,
+            vec!["Ciao".to_owned(), "Mondo".to_owned()],
+        );
+
+        let (_, texts) = extract_text_nodes(&document);
+        assert_eq!(texts, vec!["Ciao".to_owned(), "Mondo".to_owned()]);
+    }
+
+    #[test]
+    fn serialize_fragment_round_trips_without_wrapping_tags() {
+        let document = Html::parse_fragment("<p>Ciao</p>");
+
+        assert_eq!(serialize_fragment(&document), "<p>Ciao</p>");
+    }
+
+    #[test]
+    fn serialize_fragment_preserves_multiple_top_level_nodes() {
+        let document = Html::parse_fragment("<p>Ciao</p><p>Mondo</p>");
+
+        assert_eq!(serialize_fragment(&document), "<p>Ciao</p><p>Mondo</p>");
+    }
+}
@@ -1,13 +1,17 @@
+mod cache;
 mod context;
+mod grpc;
+mod html;
 mod settings;
 mod translation_api;
 mod translator;
 
-use std::net::Ipv6Addr;
+use std::{io, net::Ipv6Addr, sync::Arc};
 
 use actix_web::{get, web::Data, App, HttpResponse, HttpServer, Responder};
 use context::prepare_app_context;
 use settings::SETTINGS;
+use tonic::transport::Server as GrpcServer;
 use tracing_actix_web::TracingLogger;
 use tracing_subscriber::EnvFilter;
 
@@ -15,6 +19,9 @@ use crate::open_api::OPEN_API;
 
 mod open_api;
 
+/// Address the gRPC `Translation` service listens on, alongside the REST API.
+const GRPC_ADDR: &str = "[::]:50051";
+
 #[get("/openapi.json")]
 async fn openapi_json() -> impl Responder {
     HttpResponse::Ok().json(&*OPEN_API)
@@ -29,16 +36,29 @@ async fn main() -> std::io::Result<()> {
     // Force settings evaluation in main
     let _ = SETTINGS.path;
 
-    let app_context = Data::new(prepare_app_context());
+    let app_context = Arc::new(prepare_app_context().await);
+    let rest_app_context = Data::from(Arc::clone(&app_context));
 
-    HttpServer::new(move || {
+    let http_server = HttpServer::new(move || {
         App::new()
             .wrap(TracingLogger::default())
-            .configure(translation_api::configure(app_context.clone()))
+            .configure(translation_api::configure(rest_app_context.clone()))
             .service(openapi_json)
     })
     // .bind((Ipv4Addr::UNSPECIFIED, 8080))?
     .bind((Ipv6Addr::UNSPECIFIED, 8080))?
-    .run()
-    .await
+    .run();
+
+    let grpc_service = grpc::TranslationService::new(app_context).into_server();
+    let grpc_server = GrpcServer::builder()
+        .add_service(grpc_service)
+        .serve(GRPC_ADDR.parse().expect("GRPC_ADDR must be a valid socket address"));
+
+    // Run the REST and gRPC servers side by side; either one failing tears down the process.
+    tokio::try_join!(
+        async { http_server.await },
+        async { grpc_server.await.map_err(|error| io::Error::new(io::ErrorKind::Other, error)) },
+    )?;
+
+    Ok(())
 }
@@ -1,4 +1,4 @@
-use crate::translation_api;
+use crate::{translation_api, translator};
 use utoipa::OpenApi;
 
 use once_cell::sync::Lazy;
@@ -7,12 +7,22 @@ use once_cell::sync::Lazy;
 #[openapi(
     paths(
         translation_api::translate,
+        translation_api::translate_batch,
+        translation_api::translate_chain,
+        translation_api::health,
     ),
     components(
         schemas(
-          translation_api::TranslationRequest, 
-          translation_api::SupportedLanguages, 
-          translation_api::TranslationResponse, 
+          translation_api::TranslationRequest,
+          translation_api::ContentType,
+          translation_api::BatchTranslationRequest,
+          translation_api::ChainTranslationRequest,
+          translation_api::ChainTranslationResponse,
+          translation_api::TranslationResponse,
+          translation_api::TranslatorHealth,
+          translation_api::HealthResponse,
+          translator::Language,
+          translator::LanguagePair,
           // translation_api::ErrorResponse,
         )
     ),
@@ -1,21 +1,107 @@
-use std::{io, path::PathBuf};
+use std::{env, fs, io, path::PathBuf, time::Duration};
 
 use once_cell::sync::Lazy;
 use serde::Deserialize;
+use tracing::warn;
+
+use crate::translator::Language;
+
+/// Default per-request deadline, in milliseconds, before a translation is aborted
+/// with a `TranslatorError::Timeout`.
+const DEFAULT_REQUEST_TIMEOUT_MS: u64 = 30_000;
+
+/// Default number of times a transient model error is retried before giving up.
+const DEFAULT_MAX_RETRIES: u32 = 2;
+
+/// Name of the optional JSON file, relative to `Settings.path`, declaring the
+/// configured translation pairs.
+const LANGUAGE_PAIRS_FILE: &str = "language_pairs.json";
+
+/// A single configured translation direction: which languages it translates between,
+/// and where to find the corresponding Marian model on disk.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LanguagePairConfig {
+    pub from: Language,
+    pub to: Language,
+    pub model_dir: String,
+}
+
+/// The built-in translation pairs, used when no `language_pairs.json` is present.
+fn default_language_pairs() -> Vec<LanguagePairConfig> {
+    vec![
+        LanguagePairConfig {
+            from: Language::English,
+            to: Language::Italian,
+            model_dir: "opus-mt-en-ROMANCE".to_owned(),
+        },
+        LanguagePairConfig {
+            from: Language::Italian,
+            to: Language::English,
+            model_dir: "opus-mt-ROMANCE-en".to_owned(),
+        },
+    ]
+}
 
 #[derive(Debug, Deserialize)]
 pub struct Settings {
     pub path: PathBuf,
+    /// Maximum time, in milliseconds, a single translation request is allowed to take
+    /// before the caller receives a `TranslatorError::Timeout`.
+    pub request_timeout_ms: u64,
+    /// How many times a transient model error is retried before the request fails.
+    pub max_retries: u32,
+    /// The translation pairs to spawn translators for. Falls back to
+    /// [`default_language_pairs`] when `language_pairs.json` is absent or invalid.
+    pub language_pairs: Vec<LanguagePairConfig>,
 }
 
 impl Settings {
     pub fn new() -> Result<Self, io::Error> {
         use std::env::current_dir;
 
+        let path = current_dir()?;
+
+        let request_timeout_ms = env::var("TRANSLATOR_REQUEST_TIMEOUT_MS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(DEFAULT_REQUEST_TIMEOUT_MS);
+
+        let max_retries = env::var("TRANSLATOR_MAX_RETRIES")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(DEFAULT_MAX_RETRIES);
+
+        let language_pairs = Self::load_language_pairs(&path);
+
         Ok(Settings {
-            path: current_dir()?,
+            path,
+            request_timeout_ms,
+            max_retries,
+            language_pairs,
         })
     }
+
+    /// The per-request deadline as a `Duration`.
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms)
+    }
+
+    fn load_language_pairs(path: &PathBuf) -> Vec<LanguagePairConfig> {
+        let file_path = path.join(LANGUAGE_PAIRS_FILE);
+
+        let Ok(contents) = fs::read_to_string(&file_path) else {
+            return default_language_pairs();
+        };
+
+        match serde_json::from_str(&contents) {
+            Ok(language_pairs) => language_pairs,
+            Err(error) => {
+                warn!("Failed to parse {file_path:?}, falling back to defaults: {error}");
+                default_language_pairs()
+            }
+        }
+    }
 }
 
 pub static SETTINGS: Lazy<Settings> = Lazy::new(|| Settings::new().unwrap());
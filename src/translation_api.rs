@@ -4,34 +4,33 @@ use utoipa::ToSchema;
 
 use crate::{
     context::{self, AppContext},
-    translator::TranslatorError,
+    html,
+    translator::{Language, LanguagePair, Readiness, TranslationChain, Translator, TranslatorError},
 };
 
 use actix_web::{
+    get,
     http::StatusCode,
     post,
     web::{Data, Json, ServiceConfig},
     HttpResponse, ResponseError,
 };
 
-/// Enum representing supported languages for translation.
-#[derive(Deserialize, Serialize, ToSchema)]
-pub enum SupportedLanguages {
-    Italian,
-    English,
-}
-
 /// Enum representing possible error responses.
 #[derive(Error, Debug)]
 pub enum ErrorResponse {
     #[error("An unspecified internal error occurred: {0}")]
     TranslatorError(#[from] TranslatorError),
+
+    #[error("No translator configured for {from:?} -> {to:?}")]
+    UnsupportedLanguagePair { from: Language, to: Language },
 }
 
 impl ResponseError for ErrorResponse {
     fn status_code(&self) -> StatusCode {
         match &self {
             Self::TranslatorError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::UnsupportedLanguagePair { .. } => StatusCode::BAD_REQUEST,
         }
     }
 
@@ -40,6 +39,27 @@ impl ResponseError for ErrorResponse {
     }
 }
 
+/// Looks up the translator for `pair`, returning a `BAD_REQUEST`-mapped error when the
+/// pair isn't configured rather than panicking or silently picking a default.
+fn resolve_translator(app_context: &AppContext, pair: LanguagePair) -> Result<&Translator, ErrorResponse> {
+    app_context
+        .translator_for(pair)
+        .ok_or(ErrorResponse::UnsupportedLanguagePair {
+            from: pair.from,
+            to: pair.to,
+        })
+}
+
+/// Enum representing the kind of content being translated.
+#[derive(Deserialize, Serialize, ToSchema, Default, PartialEq, Eq)]
+pub enum ContentType {
+    /// Plain text, translated as-is.
+    #[default]
+    Plain,
+    /// An HTML fragment; only text nodes are translated, tags and attributes are preserved.
+    Html,
+}
+
 /// Struct representing a translation request.
 #[derive(Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -49,7 +69,13 @@ pub struct TranslationRequest {
     pub text: String,
     /// The source language of the text.
     #[schema(example = "Italian")]
-    pub from_language: SupportedLanguages,
+    pub from_language: Language,
+    /// The target language of the text.
+    #[schema(example = "English")]
+    pub to_language: Language,
+    /// The kind of content `text` holds. Defaults to `Plain`.
+    #[serde(default)]
+    pub content_type: ContentType,
 }
 
 /// Struct representing a translation response.
@@ -61,10 +87,53 @@ pub struct TranslationResponse {
     pub translation: String,
 }
 
+/// Struct representing a batch translation request.
+#[derive(Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchTranslationRequest {
+    /// The texts to be translated.
+    #[schema(example = "[\"Ciao, come stai?\", \"Buongiorno\"]")]
+    pub texts: Vec<String>,
+    /// The source language of the texts.
+    #[schema(example = "Italian")]
+    pub from_language: Language,
+    /// The target language of the texts.
+    #[schema(example = "English")]
+    pub to_language: Language,
+}
+
+/// Struct representing a chained / "telephone" translation request.
+#[derive(Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainTranslationRequest {
+    /// The text to be translated through the chain.
+    #[schema(example = "Ciao, come stai?")]
+    pub text: String,
+    /// The ordered hops the text is translated through, e.g. Italian -> English -> Italian.
+    #[schema(example = "[{\"from\": \"Italian\", \"to\": \"English\"}, {\"from\": \"English\", \"to\": \"Italian\"}]")]
+    pub hops: Vec<LanguagePair>,
+    /// Whether to include the intermediate translation after each hop, for debugging.
+    #[serde(default)]
+    pub include_intermediate: bool,
+}
+
+/// Struct representing a chained / "telephone" translation response.
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainTranslationResponse {
+    /// The final translation, after all hops have been applied.
+    #[schema(example = "Hello, how are you?")]
+    pub translation: String,
+    /// The translation produced after each hop, in order, when requested.
+    pub intermediate: Option<Vec<String>>,
+}
+
 /// Translate a text from a specified language to English or Italian.
 ///
 /// This function takes a translation request as a JSON payload and translates the given text
-/// based on the specified source language to either English or Italian.
+/// from the specified source language to the specified target language. When `contentType` is
+/// `Html`, `text` is parsed as an HTML fragment: only its text nodes are sent for translation,
+/// and the result is re-inserted into the original markup so tags and attributes are untouched.
 ///
 /// # Parameters
 ///
@@ -84,7 +153,8 @@ pub struct TranslationResponse {
 /// POST /translate
 /// {
 ///     "text": "Ciao, come stai?",
-///     "fromLanguage": "Italian"
+///     "fromLanguage": "Italian",
+///     "toLanguage": "English"
 /// }
 /// ```
 ///
@@ -108,19 +178,240 @@ async fn translate(
     let TranslationRequest {
         text,
         from_language,
+        to_language,
+        content_type,
     } = translation.into_inner();
 
-    let translation = match from_language {
-        SupportedLanguages::Italian => app_context.it_en.translate(text).await,
-        SupportedLanguages::English => app_context.en_it.translate(text).await,
-    }?
-    .join(" ")
-    .trim()
-    .to_owned();
+    let translator = resolve_translator(
+        &app_context,
+        LanguagePair {
+            from: from_language,
+            to: to_language,
+        },
+    )?;
+
+    let translation = match content_type {
+        ContentType::Plain => translator.translate(text).await?.join(" ").trim().to_owned(),
+        ContentType::Html => {
+            let mut document = scraper::Html::parse_fragment(&text);
+            let (ids, texts) = html::extract_text_nodes(&document);
+
+            let translations = translator
+                .translate_batch(texts)
+                .await?
+                .into_iter()
+                .map(|translation| translation.trim().to_owned())
+                .collect();
+
+            html::reinsert_translations(&mut document, &ids, translations);
+            html::serialize_fragment(&document)
+        }
+    };
 
     Ok(Json(TranslationResponse { translation }))
 }
 
+/// Translate a batch of texts between a configured pair of languages.
+///
+/// This function takes a list of texts as a JSON payload and translates all of them
+/// in a single pass over the underlying model, using Marian's native multi-sentence
+/// inference instead of one round-trip per text.
+///
+/// # Parameters
+///
+/// - `translation`: JSON payload containing the texts and source language for translation.
+///
+/// # Returns
+///
+/// Returns a JSON response with one translation per input text, in order, or an error
+/// response if translation fails.
+///
+/// # Errors
+///
+/// If translation encounters an error, it will return an error response with an appropriate status code.
+///
+/// # Example
+///
+/// ```
+/// POST /translate/batch
+/// {
+///     "texts": ["Ciao, come stai?", "Buongiorno"],
+///     "fromLanguage": "Italian",
+///     "toLanguage": "English"
+/// }
+/// ```
+///
+/// Response:
+/// ```
+/// [
+///     { "translation": "Hello, how are you?" },
+///     { "translation": "Good morning" }
+/// ]
+/// ```
+#[utoipa::path(
+    request_body = BatchTranslationRequest,
+    responses(
+        (status = 200, description = "Batch translation result", body = [TranslationResponse])
+    )
+)]
+#[post("/translate/batch")]
+async fn translate_batch(
+    app_context: Data<context::AppContext>,
+    translation: Json<BatchTranslationRequest>,
+) -> Result<Json<Vec<TranslationResponse>>, ErrorResponse> {
+    let BatchTranslationRequest {
+        texts,
+        from_language,
+        to_language,
+    } = translation.into_inner();
+
+    let translator = resolve_translator(
+        &app_context,
+        LanguagePair {
+            from: from_language,
+            to: to_language,
+        },
+    )?;
+
+    let translations = translator
+        .translate_batch(texts)
+        .await?
+        .into_iter()
+        .map(|translation| TranslationResponse {
+            translation: translation.trim().to_owned(),
+        })
+        .collect();
+
+    Ok(Json(translations))
+}
+
+/// Translate a text through a chain of hops, e.g. Italian -> English -> Italian.
+///
+/// This function feeds the output of each hop as the input of the next, joining and
+/// trimming between hops exactly as `translate` already does, reusing the existing
+/// `AppContext` translators without spawning new models. Useful for round-trip /
+/// "telephone" translation experiments.
+///
+/// # Parameters
+///
+/// - `translation`: JSON payload containing the text, the ordered hops, and whether
+///   to include intermediate results.
+///
+/// # Returns
+///
+/// Returns a JSON response with the final translation and, if requested, the
+/// translation produced after each hop.
+///
+/// # Errors
+///
+/// If any hop's translation fails, it will return an error response with an appropriate status code.
+///
+/// # Example
+///
+/// ```
+/// POST /translate/chain
+/// {
+///     "text": "Ciao, come stai?",
+///     "hops": [
+///         { "from": "Italian", "to": "English" },
+///         { "from": "English", "to": "Italian" }
+///     ]
+/// }
+/// ```
+#[utoipa::path(
+    request_body = ChainTranslationRequest,
+    responses(
+        (status = 200, description = "Chained translation result", body = ChainTranslationResponse)
+    )
+)]
+#[post("/translate/chain")]
+async fn translate_chain(
+    app_context: Data<context::AppContext>,
+    translation: Json<ChainTranslationRequest>,
+) -> Result<Json<ChainTranslationResponse>, ErrorResponse> {
+    let ChainTranslationRequest {
+        text,
+        hops,
+        include_intermediate,
+    } = translation.into_inner();
+
+    let chain = TranslationChain::new(hops);
+
+    let mut current = text;
+    let mut intermediate = include_intermediate.then(Vec::new);
+
+    for hop in chain.hops {
+        current = resolve_translator(&app_context, hop)?
+            .translate(current)
+            .await?
+            .join(" ")
+            .trim()
+            .to_owned();
+
+        if let Some(intermediate) = intermediate.as_mut() {
+            intermediate.push(current.clone());
+        }
+    }
+
+    Ok(Json(ChainTranslationResponse {
+        translation: current,
+        intermediate,
+    }))
+}
+
+/// Readiness of a single configured translator, as reported by `/health`.
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslatorHealth {
+    pub from: Language,
+    pub to: Language,
+    pub ready: bool,
+    pub error: Option<String>,
+}
+
+/// Struct representing the `/health` response.
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthResponse {
+    pub translators: Vec<TranslatorHealth>,
+}
+
+/// Reports readiness of every configured translator, so orchestrators can gate
+/// traffic until the underlying Marian models have finished loading.
+///
+/// # Returns
+///
+/// Returns a JSON response listing, for each configured language pair, whether its
+/// translator is ready, still initializing, or failed to load.
+#[utoipa::path(
+    responses(
+        (status = 200, description = "Readiness of every configured translator", body = HealthResponse)
+    )
+)]
+#[get("/health")]
+async fn health(app_context: Data<AppContext>) -> Json<HealthResponse> {
+    let translators = app_context
+        .readiness()
+        .into_iter()
+        .map(|(pair, readiness)| {
+            let (ready, error) = match readiness {
+                Readiness::Ready => (true, None),
+                Readiness::Initializing => (false, None),
+                Readiness::Failed(reason) => (false, Some(reason)),
+            };
+
+            TranslatorHealth {
+                from: pair.from,
+                to: pair.to,
+                ready,
+                error,
+            }
+        })
+        .collect();
+
+    Json(HealthResponse { translators })
+}
+
 /// Configure Actix Web service with the provided application context.
 ///
 /// This function configures an Actix Web service with the provided `AppContext`, allowing it to
@@ -145,6 +436,11 @@ async fn translate(
 #[tracing::instrument]
 pub fn configure(app_context: Data<AppContext>) -> impl FnOnce(&mut ServiceConfig) {
     |config: &mut ServiceConfig| {
-        config.app_data(app_context).service(translate);
+        config
+            .app_data(app_context)
+            .service(translate)
+            .service(translate_batch)
+            .service(translate_chain)
+            .service(health);
     }
 }
@@ -1,29 +1,35 @@
 use std::{
+    collections::HashMap,
     path::PathBuf,
     sync::{
         atomic::AtomicBool,
-        mpsc::{self},
+        mpsc::{self, RecvTimeoutError},
     },
     sync::{atomic::Ordering, Arc},
     thread::{self, JoinHandle},
+    time::{Duration, Instant},
 };
 
 use rust_bert::{
     pipelines::{
         common::{ModelResource, ModelType},
-        translation::{Language, TranslationConfig, TranslationModel, TranslationModelBuilder},
+        translation::{
+            Language as RustBertLanguage, TranslationConfig, TranslationModel,
+            TranslationModelBuilder,
+        },
     },
     resources::LocalResource,
     RustBertError,
 };
+use serde::{Deserialize, Serialize};
 use tch::Device;
-use tokio::sync::oneshot;
+use tokio::sync::{oneshot, watch};
 
 use thiserror::Error;
 use tracing::debug;
 use utoipa::ToSchema;
 
-use crate::settings::SETTINGS;
+use crate::{cache, settings::SETTINGS};
 
 /// Custom error type for the Translator.
 #[derive(Error, Debug, ToSchema)]
@@ -36,38 +42,134 @@ pub enum TranslatorError {
 
     #[error("Failed to send a message")]
     SendError,
+
+    #[error("Translation request timed out")]
+    Timeout,
+
+    #[error("No translator configured for {from:?} -> {to:?}")]
+    UnsupportedLanguagePair { from: Language, to: Language },
+
+    #[error("Translator failed to initialize: {0}")]
+    InitializationFailed(String),
 }
 
 /// Result type for translation operations using the Translator.
 type TranslationModelResult = Result<Vec<String>, TranslatorError>;
 
+/// A unit of work submitted to the translator thread: either a single text or a
+/// batch of texts to be translated together in one pass over the model.
+#[derive(Debug)]
+enum TranslationRequest {
+    Single(String),
+    Batch(Vec<String>),
+}
+
 /// Message type for the internal channel, used to pass texts and return value senders.
-type Message = (String, oneshot::Sender<TranslationModelResult>);
+type Message = (TranslationRequest, oneshot::Sender<TranslationModelResult>);
+
+/// A language supported by the configured translation models. This mirrors (a subset
+/// of) `rust_bert`'s own `Language` pipeline enum; the Marian ROMANCE models already
+/// cover these, so new pairs can be configured via `Settings` without code changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, ToSchema)]
+pub enum Language {
+    English,
+    Italian,
+    French,
+    Spanish,
+    Portuguese,
+}
+
+impl Language {
+    /// Converts to the corresponding `rust_bert` pipeline language.
+    fn to_rust_bert(self) -> RustBertLanguage {
+        match self {
+            Language::English => RustBertLanguage::English,
+            Language::Italian => RustBertLanguage::Italian,
+            Language::French => RustBertLanguage::French,
+            Language::Spanish => RustBertLanguage::Spanish,
+            Language::Portuguese => RustBertLanguage::Portuguese,
+        }
+    }
+}
 
-/// Represents the direction of translation.
-#[derive(Debug, Clone, Copy)]
-pub enum TranslationDirection {
-    EnglishToItalian,
-    ItalianToEnglish,
+/// A source/target language pair, used to key the configured translators and to
+/// reference a hop in a [`TranslationChain`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LanguagePair {
+    pub from: Language,
+    pub to: Language,
+}
+
+/// Represents a configured translation direction: the language pair it serves, and
+/// where to load its Marian model from.
+#[derive(Debug, Clone)]
+pub struct TranslationDirection {
+    pub pair: LanguagePair,
+    pub model_dir: String,
+}
+
+impl TranslationDirection {
+    /// Builds a new translation direction for the given language pair and model directory.
+    pub fn new(from: Language, to: Language, model_dir: String) -> Self {
+        Self {
+            pair: LanguagePair { from, to },
+            model_dir,
+        }
+    }
+}
+
+/// An ordered sequence of translation hops, e.g. Italian -> English -> Italian,
+/// used to produce "round-trip" / "telephone" translations: the output of each
+/// hop is fed as the input of the next.
+#[derive(Debug, Clone)]
+pub struct TranslationChain {
+    pub hops: Vec<LanguagePair>,
+}
+
+impl TranslationChain {
+    /// Builds a new chain from an ordered list of hops.
+    pub fn new(hops: Vec<LanguagePair>) -> Self {
+        Self { hops }
+    }
+}
+
+/// Reports whether a `Translator`'s model has finished loading.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Readiness {
+    /// The model is still being loaded from disk.
+    Initializing,
+    /// The model loaded successfully and is serving requests.
+    Ready,
+    /// The model failed to load; the translator will never become ready.
+    Failed(String),
 }
 
 /// The Translator struct is used to facilitate text translation.
 #[derive(Debug)]
 pub struct Translator {
+    pair: LanguagePair,
     sender: mpsc::SyncSender<Message>,
     handle: JoinHandle<Result<(), TranslatorError>>,
     stop_flag: Arc<AtomicBool>, // Flag to signal thread termination
+    /// Exact-match source -> translation overrides, loaded once at startup.
+    glossary: HashMap<String, String>,
+    /// Reports whether the model has finished loading; updated once by the runner thread.
+    readiness: watch::Receiver<Readiness>,
 }
 
 /// The default buffer length for the message channel.
 const BUFFER_LENGTH: usize = 100;
 
+/// How long the runner blocks on `recv_timeout` between checks of the stop flag.
+const RUNNER_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
 impl Translator {
     /// Spawns a new Translator instance for the specified translation direction.
     ///
     /// # Arguments
     ///
-    /// * `direction` - The direction of translation (e.g., English to Italian).
+    /// * `direction` - The direction of translation (language pair plus model directory).
     ///
     /// # Returns
     ///
@@ -77,16 +179,26 @@ impl Translator {
         debug!("Spawning a new Translator instance for {:?}", direction);
         let (sender, receiver) = mpsc::sync_channel(BUFFER_LENGTH);
 
+        let pair = direction.pair;
+        let glossary = cache::load_glossary(pair);
+
         // Create a stop flag shared between the main thread and the translator thread.
         let stop_flag = Arc::new(AtomicBool::new(false));
 
+        let (readiness_sender, readiness) = watch::channel(Readiness::Initializing);
+
         let stop_flag_clone = Arc::clone(&stop_flag);
-        let handle = thread::spawn(move || Self::runner(receiver, direction, stop_flag_clone));
+        let handle = thread::spawn(move || {
+            Self::runner(receiver, direction, stop_flag_clone, readiness_sender)
+        });
 
         Self {
+            pair,
             sender,
             handle,
             stop_flag,
+            glossary,
+            readiness,
         }
     }
 
@@ -96,22 +208,13 @@ impl Translator {
         receiver: mpsc::Receiver<Message>,
         direction: TranslationDirection,
         stop_flag: Arc<AtomicBool>,
+        readiness_sender: watch::Sender<Readiness>,
     ) -> Result<(), TranslatorError> {
         debug!("Initialising model");
 
-        let mut base_path = PathBuf::from(&SETTINGS.path);
-
-        // Create a translation model based on the specified direction
-        let (source_lang, target_lang) = match direction {
-            TranslationDirection::EnglishToItalian => {
-                base_path.push("opus-mt-en-ROMANCE");
-                (Language::English, Language::Italian)
-            }
-            TranslationDirection::ItalianToEnglish => {
-                base_path.push("opus-mt-ROMANCE-en");
-                (Language::Italian, Language::English)
-            }
-        };
+        let base_path = PathBuf::from(&SETTINGS.path).join(&direction.model_dir);
+        let source_lang = direction.pair.from.to_rust_bert();
+        let target_lang = direction.pair.to.to_rust_bert();
 
         debug!("Derived base_path {base_path:?}");
 
@@ -137,43 +240,159 @@ impl Translator {
         );
         debug!("Derived translation_config");
 
-        let model = TranslationModel::new(translation_config)?;
+        let model = match TranslationModel::new(translation_config) {
+            Ok(model) => Arc::new(model),
+            Err(error) => {
+                let error = TranslatorError::RustBertError(error);
+                // Best-effort: if every receiver already dropped, there's nothing left to notify.
+                let _ = readiness_sender.send(Readiness::Failed(error.to_string()));
+                return Err(error);
+            }
+        };
         debug!("Initialised model");
 
-        // Process incoming translation requests
-        while !stop_flag.load(Ordering::Relaxed) {
-            match receiver.try_recv() {
-                Ok((text, sender)) => {
-                    // Add trace for receiving a translation request
-                    debug!("Received translation request: {:?}", text);
+        let _ = readiness_sender.send(Readiness::Ready);
+
+        // Process incoming translation requests. Blocking on `recv_timeout` instead of
+        // busy-polling with `try_recv` lets the thread sleep between requests, while the
+        // bounded wait still gives us a chance to observe the stop flag.
+        //
+        // `pending_worker` tracks a model call that outlived its timeout: libtorch models
+        // aren't safe to call concurrently from two threads, so before starting the next
+        // request we join whatever worker is still running rather than let it overlap with
+        // a fresh call against the same `Arc<TranslationModel>`.
+        let mut pending_worker: Option<JoinHandle<()>> = None;
+
+        loop {
+            if stop_flag.load(Ordering::Relaxed) {
+                break;
+            }
+
+            if let Some(worker) = pending_worker.take() {
+                debug!("Waiting for a timed-out model call to finish before continuing");
+                let _ = worker.join();
+            }
 
-                    let translation = model
-                        .translate(&[&text], source_lang, target_lang)
-                        .map_err(|error| TranslatorError::RustBertError(error));
+            match receiver.recv_timeout(RUNNER_POLL_INTERVAL) {
+                Ok((request, sender)) => {
+                    // Add trace for receiving a translation request
+                    debug!("Received translation request: {:?}", request);
+
+                    let (translation, worker) = Self::translate_with_retries(
+                        &model,
+                        &request,
+                        source_lang,
+                        target_lang,
+                        SETTINGS.request_timeout(),
+                        SETTINGS.max_retries,
+                    );
+                    pending_worker = worker;
 
                     // Add trace for processing the translation request
-                    debug!("Processing translation request: {:?}", &text);
+                    debug!("Processing translation request: {:?}", &request);
 
                     sender
                         .send(translation)
                         .map_err(|_| TranslatorError::SendError)?;
 
                     // Add trace for completing the translation request
-                    debug!("Completed translation request: {:?}", &text);
+                    debug!("Completed translation request: {:?}", &request);
                 }
-                Err(mpsc::TryRecvError::Empty) => {
-                    // No messages in the channel, continue processing
+                Err(RecvTimeoutError::Timeout) => {
+                    // No messages within the poll interval, loop back and check the stop flag.
                 }
-                Err(mpsc::TryRecvError::Disconnected) => {
+                Err(RecvTimeoutError::Disconnected) => {
                     // Channel disconnected, exit the loop
                     break;
                 }
             }
         }
 
+        if let Some(worker) = pending_worker.take() {
+            let _ = worker.join();
+        }
+
         Ok(())
     }
 
+    /// Runs a single translation request against the model, retrying transient
+    /// `RustBertError`s up to `max_retries` times. `timeout` bounds the *total* time spent
+    /// across all attempts (not each attempt individually), so it still caps overall
+    /// request latency regardless of `max_retries`.
+    ///
+    /// Returns the translation result alongside a `JoinHandle` for the still-running model
+    /// call if the final attempt timed out: the model isn't safe to call again until that
+    /// thread actually finishes, so the caller must join it before starting new work.
+    fn translate_with_retries(
+        model: &Arc<TranslationModel>,
+        request: &TranslationRequest,
+        source_lang: RustBertLanguage,
+        target_lang: RustBertLanguage,
+        timeout: Duration,
+        max_retries: u32,
+    ) -> (TranslationModelResult, Option<JoinHandle<()>>) {
+        let texts: Vec<String> = match request {
+            TranslationRequest::Single(text) => vec![text.clone()],
+            TranslationRequest::Batch(texts) => texts.clone(),
+        };
+
+        let deadline = Instant::now() + timeout;
+        let mut attempt = 0;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return (Err(TranslatorError::Timeout), None);
+            }
+
+            match Self::translate_with_timeout(model, &texts, source_lang, target_lang, remaining)
+            {
+                (Err(TranslatorError::RustBertError(error)), worker) if attempt < max_retries => {
+                    // The attempt finished (it didn't time out), so there's no worker left running.
+                    debug_assert!(worker.is_none());
+                    attempt += 1;
+                    debug!(
+                        "Retrying translation request after transient error (attempt {}/{}): {:?}",
+                        attempt, max_retries, error
+                    );
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Runs the model call on a dedicated thread so it can be bounded by `timeout`: the
+    /// calling thread gives up and returns `TranslatorError::Timeout` if the model hasn't
+    /// replied in time, instead of stalling the caller indefinitely.
+    ///
+    /// The spawned thread is not cancelled on timeout (the underlying model call can't be
+    /// interrupted), so on timeout this also returns a `JoinHandle` for it. The caller must
+    /// join that handle before issuing another call against the same model: two concurrent
+    /// calls into libtorch on one model are not safe.
+    fn translate_with_timeout(
+        model: &Arc<TranslationModel>,
+        texts: &[String],
+        source_lang: RustBertLanguage,
+        target_lang: RustBertLanguage,
+        timeout: Duration,
+    ) -> (TranslationModelResult, Option<JoinHandle<()>>) {
+        let model = Arc::clone(model);
+        let texts = texts.to_vec();
+        let (sender, receiver) = mpsc::channel();
+
+        let worker = thread::spawn(move || {
+            let text_refs: Vec<&str> = texts.iter().map(String::as_str).collect();
+            let result = model
+                .translate(&text_refs, source_lang, target_lang)
+                .map_err(TranslatorError::RustBertError);
+            let _ = sender.send(result);
+        });
+
+        match receiver.recv_timeout(timeout) {
+            Ok(result) => (result, None),
+            Err(_) => (Err(TranslatorError::Timeout), Some(worker)),
+        }
+    }
+
     /// Translates the given text and returns the translation result.
     ///
     /// # Arguments
@@ -188,21 +407,91 @@ impl Translator {
         // Add trace for initiating a translation request
         debug!("Initiating translation request: {:?}", &text);
 
+        // The glossary holds exact-match operator overrides, so it takes precedence
+        // over both the translation-memory cache and the model itself.
+        if let Some(translation) = self.glossary.get(&text) {
+            debug!("Glossary hit for translation request: {:?}", &text);
+            return Ok(vec![translation.clone()]);
+        }
+
+        if let Some(translation) = cache::get(self.pair, &text) {
+            debug!("Translation-memory hit for translation request: {:?}", &text);
+            return Ok(vec![translation]);
+        }
+
         let (sender, receiver) = oneshot::channel();
         self.sender
-            .send((text.clone(), sender))
+            .send((TranslationRequest::Single(text.clone()), sender))
             .map_err(|_| TranslatorError::ThreadJoinError)?;
 
         let translation_result = receiver
             .await
             .map_err(|_| TranslatorError::ThreadJoinError)?;
 
+        if let Ok(translation) = &translation_result {
+            let normalized = translation.join(" ").trim().to_owned();
+            cache::insert(self.pair, &text, normalized);
+        }
+
         // Add trace for completing the translation request
         debug!("Completed translation request: {:?}", text);
 
         translation_result
     }
 
+    /// Translates a batch of texts in a single pass over the model.
+    ///
+    /// Unlike [`Translator::translate`], which sends one text per round-trip through
+    /// the channel, this sends the whole slice as a single `Message` so the underlying
+    /// `TranslationModel::translate` call runs once over the batch. The result is a
+    /// `Vec<String>` with one translation per input text, in the same order. Unlike
+    /// [`Translator::translate`], this does not consult the glossary or translation-memory
+    /// cache: batches are assumed to already be whole, mostly-unique payloads.
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - The texts to be translated together.
+    #[tracing::instrument]
+    pub async fn translate_batch(&self, texts: Vec<String>) -> TranslationModelResult {
+        // Add trace for initiating a batch translation request
+        debug!("Initiating batch translation request: {:?}", &texts);
+
+        let (sender, receiver) = oneshot::channel();
+        self.sender
+            .send((TranslationRequest::Batch(texts.clone()), sender))
+            .map_err(|_| TranslatorError::ThreadJoinError)?;
+
+        let translation_result = receiver
+            .await
+            .map_err(|_| TranslatorError::ThreadJoinError)?;
+
+        // Add trace for completing the batch translation request
+        debug!("Completed batch translation request: {:?}", texts);
+
+        translation_result
+    }
+
+    /// Returns the current readiness state without waiting.
+    pub fn readiness(&self) -> Readiness {
+        self.readiness.borrow().clone()
+    }
+
+    /// Resolves once the model has finished loading, or has failed to load.
+    pub async fn ready(&self) -> Result<(), TranslatorError> {
+        let mut receiver = self.readiness.clone();
+        loop {
+            match receiver.borrow().clone() {
+                Readiness::Ready => return Ok(()),
+                Readiness::Failed(reason) => return Err(TranslatorError::InitializationFailed(reason)),
+                Readiness::Initializing => {}
+            }
+            receiver
+                .changed()
+                .await
+                .map_err(|_| TranslatorError::ThreadJoinError)?;
+        }
+    }
+
     /// Stops the translator thread gracefully and joins it.
     pub fn stop(self) -> Result<(), TranslatorError> {
         self.stop_flag.store(true, Ordering::Relaxed);
@@ -222,7 +511,11 @@ mod tests {
     #[actix_web::test]
     async fn test_english_to_italian_translation() {
         // Create an instance of Translator for English to Italian translation
-        let en_it = Translator::spawn(TranslationDirection::EnglishToItalian);
+        let en_it = Translator::spawn(TranslationDirection::new(
+            Language::English,
+            Language::Italian,
+            "opus-mt-en-ROMANCE".to_owned(),
+        ));
 
         // Translate the text and assert the result
         let translation_result = en_it
@@ -243,7 +536,11 @@ mod tests {
     #[actix_web::test]
     async fn test_italian_to_english_translation() {
         // Create an instance of Translator for Italian to English translation
-        let it_en = Translator::spawn(TranslationDirection::ItalianToEnglish);
+        let it_en = Translator::spawn(TranslationDirection::new(
+            Language::Italian,
+            Language::English,
+            "opus-mt-ROMANCE-en".to_owned(),
+        ));
 
         // Translate the text and assert the result
         let translation = it_en